@@ -0,0 +1,50 @@
+use crate::Perform;
+use actix_web::web::Data;
+use lemmy_api_common::{
+  activity::{ListActivities, ListActivitiesResponse},
+  context::LemmyContext,
+  sensitive::Sensitive,
+  utils::{is_admin, local_user_view_from_jwt_new},
+};
+use lemmy_db_views::activity_view::ActivityQuery;
+use lemmy_utils::{error::LemmyError, ConnectionId};
+
+/// Pages through the federation debug log. Admin only, since the stored
+/// activities may contain sensitive payloads from other instances.
+#[async_trait::async_trait(?Send)]
+impl Perform for ListActivities {
+  type Response = ListActivitiesResponse;
+
+  #[tracing::instrument(skip(context, _websocket_id))]
+  async fn perform(
+    &self,
+    context: &Data<LemmyContext>,
+    auth: Option<Sensitive<String>>,
+    _websocket_id: Option<ConnectionId>,
+  ) -> Result<ListActivitiesResponse, LemmyError> {
+    let data: &ListActivities = self;
+    let local_user_view = local_user_view_from_jwt_new(auth, context).await?;
+    is_admin(&local_user_view)?;
+
+    let actor_ap_id = data.actor_ap_id.clone();
+    let local = data.local;
+    let success = data.success;
+
+    let page = data.page;
+    let limit = data.limit;
+    let activities = ActivityQuery::builder()
+      .pool(context.pool())
+      .actor_ap_id(actor_ap_id)
+      .local(local)
+      .success(success)
+      .page(page)
+      .limit(limit)
+      .build()
+      .list()
+      .await?;
+
+    let res = ListActivitiesResponse { activities };
+
+    Ok(res)
+  }
+}
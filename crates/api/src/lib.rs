@@ -0,0 +1,36 @@
+use actix_web::{web, HttpResponse};
+use lemmy_api_common::{activity::ListActivities, context::LemmyContext, sensitive::Sensitive};
+use lemmy_utils::{error::LemmyError, ConnectionId};
+
+pub mod activity;
+pub mod post_report;
+
+/// A request that can be performed against the API, returning a serializable response.
+#[async_trait::async_trait(?Send)]
+pub trait Perform {
+  type Response: serde::ser::Serialize + Send;
+
+  async fn perform(
+    &self,
+    context: &web::Data<LemmyContext>,
+    auth: Option<Sensitive<String>>,
+    websocket_id: Option<ConnectionId>,
+  ) -> Result<Self::Response, LemmyError>;
+}
+
+/// Registers the http routes served by this crate.
+pub fn config(cfg: &mut web::ServiceConfig) {
+  cfg.service(
+    web::scope("/federation").route("/activities", web::get().to(list_activities)),
+  );
+}
+
+async fn list_activities(
+  data: web::Query<ListActivities>,
+  context: web::Data<LemmyContext>,
+) -> Result<HttpResponse, LemmyError> {
+  let data = data.into_inner();
+  let auth = Some(data.auth.clone());
+  let res = data.perform(&context, auth, None).await?;
+  Ok(HttpResponse::Ok().json(res))
+}
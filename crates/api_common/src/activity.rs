@@ -0,0 +1,24 @@
+use crate::sensitive::Sensitive;
+use lemmy_db_schema::newtypes::DbUrl;
+use lemmy_db_views::activity_view::ActivityView;
+use serde::{Deserialize, Serialize};
+
+/// Page through the federation debug log. Admin only.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ListActivities {
+  /// Only return activities from this actor's instance. Host-scoped: the
+  /// `activity` table has no actor column, so filtering is by `scheme://host/`.
+  pub actor_ap_id: Option<DbUrl>,
+  /// `Some(true)` for activities we sent, `Some(false)` for ones we received.
+  pub local: Option<bool>,
+  /// Filter by whether delivery/parsing succeeded.
+  pub success: Option<bool>,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+  pub auth: Sensitive<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ListActivitiesResponse {
+  pub activities: Vec<ActivityView>,
+}
@@ -0,0 +1,51 @@
+use crate::{
+  activity_lists::GroupInboxActivities,
+  context::WithContext,
+  http::{create_apub_response, receive_lemmy_activity, site::verify_fetch_allowed},
+  objects::community::ApubCommunity,
+};
+use actix_web::{web, HttpRequest, HttpResponse};
+use lemmy_api_common::utils::blocking;
+use lemmy_apub_lib::traits::ApubObject;
+use lemmy_db_schema::source::community::Community;
+use lemmy_utils::LemmyError;
+use lemmy_websocket::LemmyContext;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub(crate) struct CommunityQuery {
+  community_name: String,
+}
+
+/// Return the ActivityPub json representation of a local community over HTTP.
+#[tracing::instrument(skip_all)]
+pub(crate) async fn get_apub_community_http(
+  request: HttpRequest,
+  info: web::Path<CommunityQuery>,
+  context: web::Data<LemmyContext>,
+) -> Result<HttpResponse, LemmyError> {
+  // In secure mode only a signed fetch may read the community.
+  verify_fetch_allowed(&request, &context).await?;
+
+  let community: ApubCommunity = blocking(context.pool(), move |conn| {
+    Community::read_from_name(conn, &info.community_name, true)
+  })
+  .await??
+  .into();
+
+  let apub = community.into_apub(&context).await?;
+  Ok(create_apub_response(&apub))
+}
+
+/// Handler for all activities received by a community inbox.
+#[tracing::instrument(skip_all)]
+pub async fn get_apub_community_inbox(
+  request: HttpRequest,
+  payload: String,
+  context: web::Data<LemmyContext>,
+) -> Result<HttpResponse, LemmyError> {
+  receive_lemmy_activity::<WithContext<GroupInboxActivities>, ApubCommunity>(
+    request, payload, context,
+  )
+  .await
+}
@@ -0,0 +1,52 @@
+use crate::{
+  activity_lists::PersonInboxActivities,
+  context::WithContext,
+  http::{create_apub_response, receive_lemmy_activity, site::verify_fetch_allowed},
+  objects::person::ApubPerson,
+};
+use actix_web::{web, HttpRequest, HttpResponse};
+use lemmy_api_common::utils::blocking;
+use lemmy_apub_lib::traits::ApubObject;
+use lemmy_db_schema::source::person::Person;
+use lemmy_utils::LemmyError;
+use lemmy_websocket::LemmyContext;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub(crate) struct PersonQuery {
+  user_name: String,
+}
+
+/// Return the ActivityPub json representation of a local person over HTTP.
+#[tracing::instrument(skip_all)]
+pub(crate) async fn get_apub_person_http(
+  request: HttpRequest,
+  info: web::Path<PersonQuery>,
+  context: web::Data<LemmyContext>,
+) -> Result<HttpResponse, LemmyError> {
+  // In secure mode only a signed fetch may read the person.
+  verify_fetch_allowed(&request, &context).await?;
+
+  let user_name = info.into_inner().user_name;
+  let person: ApubPerson = blocking(context.pool(), move |conn| {
+    Person::find_by_name(conn, &user_name)
+  })
+  .await??
+  .into();
+
+  let apub = person.into_apub(&context).await?;
+  Ok(create_apub_response(&apub))
+}
+
+/// Handler for all activities received by a person inbox.
+#[tracing::instrument(skip_all)]
+pub async fn get_apub_person_inbox(
+  request: HttpRequest,
+  payload: String,
+  context: web::Data<LemmyContext>,
+) -> Result<HttpResponse, LemmyError> {
+  receive_lemmy_activity::<WithContext<PersonInboxActivities>, ApubPerson>(
+    request, payload, context,
+  )
+  .await
+}
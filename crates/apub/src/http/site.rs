@@ -1,21 +1,33 @@
 use crate::{
   activity_lists::SiteInboxActivities,
   context::WithContext,
-  http::{create_apub_response, receive_lemmy_activity},
+  fetcher::{object_id::ObjectId, user_or_community::UserOrCommunity},
+  http::create_apub_response,
+  http::receive_lemmy_activity,
+  local_instance,
   objects::instance::ApubSite,
   protocol::collections::empty_outbox::EmptyOutbox,
 };
 use actix_web::{web, HttpRequest, HttpResponse};
 use lemmy_api_common::utils::blocking;
-use lemmy_apub_lib::traits::ApubObject;
+use lemmy_apub_lib::{
+  inbox::ActorPublicKey,
+  signatures::{signing_actor_id, verify_signature},
+  traits::ApubObject,
+};
 use lemmy_db_schema::source::site::Site;
 use lemmy_utils::{settings::structs::Settings, LemmyError};
 use lemmy_websocket::LemmyContext;
 use url::Url;
 
 pub(crate) async fn get_apub_site_http(
+  request: HttpRequest,
   context: web::Data<LemmyContext>,
 ) -> Result<HttpResponse, LemmyError> {
+  // When secure mode is enabled, only serve the site object to a caller that
+  // signed the GET with a known actor key; otherwise serve anyone.
+  verify_fetch_allowed(&request, &context).await?;
+
   let site: ApubSite = blocking(context.pool(), Site::read_local_site)
     .await??
     .into();
@@ -34,6 +46,57 @@ pub(crate) async fn get_apub_site_outbox() -> Result<HttpResponse, LemmyError> {
   Ok(create_apub_response(&outbox))
 }
 
+/// Enforces secure mode (authorized fetch) before an object is served.
+///
+/// When `federation.secure_mode` is off this is a no-op and objects are served
+/// anonymously, preserving the previous behaviour. When it is on, the incoming
+/// `GET` must carry a valid HTTP Signature: we take the signing actor id from the
+/// library's header parser, dereference it, and verify the request against its
+/// public key. This lets an instance that blocks a remote server actually stop
+/// that server from reading its content, which exact-string blocklists cannot do.
+///
+/// Shared by all object GET handlers (site, community, person, …) so secure mode
+/// has identical semantics everywhere.
+#[tracing::instrument(skip_all)]
+pub(crate) async fn verify_fetch_allowed(
+  request: &HttpRequest,
+  context: &LemmyContext,
+) -> Result<(), LemmyError> {
+  if !context.settings().federation.secure_mode {
+    return Ok(());
+  }
+
+  // Reuse the library's signature parsing rather than hand-rolling keyId
+  // extraction, so the receive path here matches the inbox path exactly.
+  let actor_id = signing_actor_id(request)?;
+  let public_key = dereference_signing_actor(actor_id, context).await?;
+  verify_signature(request, &public_key)?;
+  Ok(())
+}
+
+/// Resolves the public key of the actor that signed an incoming fetch.
+///
+/// Authorized-fetch signatures are produced with the remote instance's Site
+/// actor (see `fetcher::sign_fetch`), but a user or community key is also valid.
+/// We therefore try the Site actor first and fall back to user/community, so the
+/// verify path accepts exactly what the send path produces.
+async fn dereference_signing_actor(
+  actor_id: Url,
+  context: &LemmyContext,
+) -> Result<String, LemmyError> {
+  let request_counter = &mut 0;
+  if let Ok(site) = ObjectId::<ApubSite>::new(actor_id.clone())
+    .dereference(context, local_instance(context), request_counter)
+    .await
+  {
+    return Ok(site.public_key().to_string());
+  }
+  let actor = ObjectId::<UserOrCommunity>::new(actor_id)
+    .dereference(context, local_instance(context), request_counter)
+    .await?;
+  Ok(actor.public_key().to_string())
+}
+
 #[tracing::instrument(skip_all)]
 pub async fn get_apub_site_inbox(
   request: HttpRequest,
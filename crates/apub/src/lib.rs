@@ -74,13 +74,13 @@ fn check_apub_id_valid(apub_id: &Url, settings: &Settings) -> Result<(), &'stati
   }
 
   if let Some(blocked) = settings.to_owned().federation.blocked_instances {
-    if blocked.contains(&domain) {
+    if domain_in_instance_list(&domain, &blocked) {
       return Err("Domain is blocked");
     }
   }
 
   if let Some(allowed) = settings.to_owned().federation.allowed_instances {
-    if !allowed.contains(&domain) {
+    if !domain_in_instance_list(&domain, &allowed) {
       return Err("Domain is not in allowlist");
     }
   }
@@ -88,6 +88,52 @@ fn check_apub_id_valid(apub_id: &Url, settings: &Settings) -> Result<(), &'stati
   Ok(())
 }
 
+/// Strips the port off a candidate domain and lowercases it, so that matching
+/// against allow/blocklist entries is case- and port-insensitive.
+fn normalize_domain(domain: &str) -> String {
+  domain
+    .rsplit_once(':')
+    .map_or(domain, |(host, _port)| host)
+    .to_lowercase()
+}
+
+/// Checks whether a single allow/blocklist entry matches `domain`.
+///
+/// Besides exact hostnames, an entry may start with `*.` to match an entire
+/// subdomain tree: `*.example.org` matches `example.org` itself as well as any
+/// `foo.example.org`, but not an unrelated `example.org.evil.com`.
+fn domain_matches_pattern(domain: &str, pattern: &str) -> bool {
+  let pattern = normalize_domain(pattern);
+  if let Some(suffix) = pattern.strip_prefix("*.") {
+    domain == suffix || domain.ends_with(&format!(".{}", suffix))
+  } else {
+    domain == pattern
+  }
+}
+
+/// Walks the suffix labels of `domain` against an allow/blocklist, supporting
+/// `*.example.org` wildcard entries and a leading `!` negation that carves an
+/// exception out of a broader wildcard (e.g. `*.example.org` together with
+/// `!evil.example.org`). A negated match always wins, so the same list produces
+/// identical semantics on both the send and receive paths.
+fn domain_in_instance_list(domain: &str, list: &[String]) -> bool {
+  let domain = normalize_domain(domain);
+  let mut matched = false;
+  for entry in list {
+    let (negated, pattern) = match entry.strip_prefix('!') {
+      Some(rest) => (true, rest),
+      None => (false, entry.as_str()),
+    };
+    if domain_matches_pattern(&domain, pattern) {
+      if negated {
+        return false;
+      }
+      matched = true;
+    }
+  }
+  matched
+}
+
 #[tracing::instrument(skip(settings))]
 pub(crate) fn check_apub_id_valid_with_strictness(
   apub_id: &Url,
@@ -111,7 +157,7 @@ pub(crate) fn check_apub_id_valid_with_strictness(
       // instance.
       allowed.push(local_instance);
 
-      if !allowed.contains(&domain) {
+      if !domain_in_instance_list(&domain, &allowed) {
         return Err(LemmyError::from_message(
           "Federation forbidden by strict allowlist",
         ));
@@ -233,20 +279,33 @@ fn generate_moderators_url(community_id: &DbUrl) -> Result<DbUrl, LemmyError> {
   Ok(Url::parse(&format!("{}/moderators", community_id))?.into())
 }
 
-/// Store a sent or received activity in the database, for logging purposes. These records are not
-/// persistent.
+/// Maps the outcome of delivering (local) or parsing (received) an activity to
+/// the `success` column recorded in the debug log, so admins can filter the log
+/// by whether an activity actually went through.
+pub(crate) fn activity_success<T, E>(outcome: &Result<T, E>) -> Option<bool> {
+  Some(outcome.is_ok())
+}
+
+/// Store a sent or received activity in the database, for the federation debug log.
+///
+/// These records are kept for the retention window configured by
+/// `federation.keep_activities_days` and pruned by the scheduled
+/// `clear_old_activities` task, so that admins can inspect them through the
+/// activity query API instead of grepping server logs. Callers pass the delivery
+/// or parse outcome via [`activity_success`] as `success`.
 #[tracing::instrument(skip(pool))]
 async fn insert_activity(
   ap_id: &Url,
   activity: serde_json::Value,
   local: bool,
   sensitive: bool,
+  success: Option<bool>,
   pool: &DbPool,
 ) -> Result<bool, LemmyError> {
   let ap_id = ap_id.to_owned().into();
   Ok(
     blocking(pool, move |conn| {
-      Activity::insert(conn, ap_id, activity, local, sensitive)
+      Activity::insert(conn, ap_id, activity, local, sensitive, success)
     })
     .await??,
   )
@@ -271,3 +330,44 @@ pub trait ActorType: ActorPublicKey {
     PublicKey::new_main_key(self.actor_id(), self.public_key().to_string())
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::{activity_success, domain_in_instance_list};
+
+  #[test]
+  fn test_wildcard_matches_apex_and_subdomains() {
+    let list = vec!["*.example.org".to_string()];
+    assert!(domain_in_instance_list("example.org", &list));
+    assert!(domain_in_instance_list("foo.example.org", &list));
+    assert!(domain_in_instance_list("a.b.example.org", &list));
+    assert!(!domain_in_instance_list("example.org.evil.com", &list));
+    assert!(!domain_in_instance_list("notexample.org", &list));
+  }
+
+  #[test]
+  fn test_exact_match_and_port_normalization() {
+    let list = vec!["example.org".to_string()];
+    assert!(domain_in_instance_list("example.org", &list));
+    assert!(domain_in_instance_list("EXAMPLE.ORG:8536", &list));
+    assert!(!domain_in_instance_list("foo.example.org", &list));
+  }
+
+  #[test]
+  fn test_negation_carves_exception() {
+    let list = vec![
+      "*.example.org".to_string(),
+      "!evil.example.org".to_string(),
+    ];
+    assert!(domain_in_instance_list("good.example.org", &list));
+    assert!(!domain_in_instance_list("evil.example.org", &list));
+  }
+
+  #[test]
+  fn test_activity_success_records_outcome() {
+    let delivered: Result<(), &str> = Ok(());
+    let failed: Result<(), &str> = Err("connection refused");
+    assert_eq!(activity_success(&delivered), Some(true));
+    assert_eq!(activity_success(&failed), Some(false));
+  }
+}
@@ -0,0 +1,49 @@
+use crate::{
+  newtypes::DbUrl,
+  source::activity::{Activity, ActivityForm},
+};
+use diesel::{insert_into, result::Error, ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl};
+use serde_json::Value;
+
+impl Activity {
+  /// Insert a sent or received activity into the debug log. On conflict nothing is written;
+  /// returns whether a new row was inserted.
+  pub fn insert(
+    conn: &PgConnection,
+    ap_id: DbUrl,
+    data: Value,
+    local: bool,
+    sensitive: bool,
+    success: Option<bool>,
+  ) -> Result<bool, Error> {
+    use crate::schema::activity::dsl::activity;
+    let form = ActivityForm {
+      data,
+      local,
+      updated: None,
+      ap_id,
+      sensitive,
+      success,
+    };
+    let rows = insert_into(activity)
+      .values(&form)
+      .on_conflict_do_nothing()
+      .execute(conn)?;
+    Ok(rows == 1)
+  }
+
+  pub fn read_from_apub_id(conn: &PgConnection, object_id: &DbUrl) -> Result<Activity, Error> {
+    use crate::schema::activity::dsl::{activity, ap_id};
+    activity.filter(ap_id.eq(object_id)).first::<Self>(conn)
+  }
+
+  /// Delete all activities published before `cutoff`, enforcing the retention window. Returns the
+  /// number of rows removed.
+  pub fn delete_older_than(
+    conn: &PgConnection,
+    cutoff: chrono::NaiveDateTime,
+  ) -> Result<usize, Error> {
+    use crate::schema::activity::dsl::{activity, published};
+    diesel::delete(activity.filter(published.lt(cutoff))).execute(conn)
+  }
+}
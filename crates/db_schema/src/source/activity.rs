@@ -0,0 +1,33 @@
+use crate::newtypes::DbUrl;
+#[cfg(feature = "full")]
+use crate::schema::activity;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
+#[cfg_attr(feature = "full", table_name = "activity")]
+pub struct Activity {
+  pub id: i32,
+  pub data: Value,
+  pub local: bool,
+  pub published: chrono::NaiveDateTime,
+  pub updated: Option<chrono::NaiveDateTime>,
+  pub ap_id: DbUrl,
+  pub sensitive: bool,
+  /// Whether delivery (local activities) or parsing (received activities) succeeded. `None` when
+  /// the outcome was not recorded.
+  pub success: Option<bool>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", table_name = "activity")]
+pub struct ActivityForm {
+  pub data: Value,
+  pub local: bool,
+  pub updated: Option<chrono::NaiveDateTime>,
+  pub ap_id: DbUrl,
+  pub sensitive: bool,
+  pub success: Option<bool>,
+}
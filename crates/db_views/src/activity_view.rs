@@ -0,0 +1,70 @@
+use diesel::{result::Error, ExpressionMethods, QueryDsl, TextExpressionMethods};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::DbUrl,
+  schema::activity,
+  source::activity::Activity,
+  utils::{get_conn, limit_and_offset, DbPool},
+};
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+use url::Url;
+
+/// A single row of the federation debug log.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ActivityView {
+  pub activity: Activity,
+}
+
+/// Pages through the stored activities, filtering by the host of a given actor, their direction
+/// (local vs remote), and whether delivery/parsing succeeded. The actor filter matches every
+/// activity whose `ap_id` starts with the actor's `scheme://host/`; it does not tell apart
+/// different actors on the same host.
+#[derive(TypedBuilder)]
+#[builder(field_defaults(default))]
+pub struct ActivityQuery<'a> {
+  #[builder(!default)]
+  pool: &'a DbPool,
+  actor_ap_id: Option<DbUrl>,
+  local: Option<bool>,
+  success: Option<bool>,
+  page: Option<i64>,
+  limit: Option<i64>,
+}
+
+impl<'a> ActivityQuery<'a> {
+  pub async fn list(self) -> Result<Vec<ActivityView>, Error> {
+    let conn = &mut get_conn(self.pool).await?;
+
+    let mut query = activity::table.into_boxed();
+
+    if let Some(local) = self.local {
+      query = query.filter(activity::local.eq(local));
+    }
+    if let Some(success) = self.success {
+      query = query.filter(activity::success.eq(success));
+    }
+    if let Some(actor_ap_id) = self.actor_ap_id {
+      // The `activity` table has no actor column, and `ap_id` is the activity's
+      // own url (`https://host/activities/...`), so we can only scope by the
+      // actor's host, not the exact actor. Match every activity sharing the
+      // actor's `scheme://host/` prefix.
+      let actor: Url = actor_ap_id.into();
+      if let Some(host) = actor.host_str() {
+        let prefix = format!("{}://{}/", actor.scheme(), host);
+        query = query.filter(activity::ap_id.ilike(format!("{}%", prefix)));
+      }
+    }
+
+    let (limit, offset) = limit_and_offset(self.page, self.limit)?;
+
+    let res = query
+      .order_by(activity::published.desc())
+      .limit(limit)
+      .offset(offset)
+      .load::<Activity>(conn)
+      .await?;
+
+    Ok(res.into_iter().map(|activity| ActivityView { activity }).collect())
+  }
+}
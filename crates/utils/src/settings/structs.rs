@@ -0,0 +1,164 @@
+use crate::settings::get_database_url_from_env;
+use doku::Document;
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv4Addr};
+use url::Url;
+
+#[derive(Debug, Deserialize, Serialize, Clone, SmartDefault, Document)]
+#[serde(default)]
+pub struct Settings {
+  /// settings related to the postgresql database
+  #[default(Default::default())]
+  pub database: DatabaseConfig,
+  /// Settings related to activitypub federation
+  #[default(Default::default())]
+  pub federation: Federation,
+  #[default(Default::default())]
+  pub captcha: CaptchaConfig,
+  /// Email sending configuration. All options except login/password are mandatory
+  #[default(None)]
+  #[doku(example = "Some(EmailConfig::default())")]
+  pub email: Option<EmailConfig>,
+  /// Parameters for automatic configuration of new instance (only used at first start)
+  #[default(None)]
+  pub setup: Option<SetupConfig>,
+  /// the domain name of your instance (mandatory)
+  #[default("unset")]
+  #[doku(example = "example.com")]
+  pub hostname: String,
+  /// Address where lemmy should listen for incoming requests
+  #[default(Ipv4Addr::new(0, 0, 0, 0).into())]
+  #[doku(as = "String")]
+  pub bind: IpAddr,
+  /// Port where lemmy should listen for incoming requests
+  #[default(8536)]
+  pub port: u16,
+  /// Whether the site is available over TLS. Needs to be true for federation to work.
+  #[default(true)]
+  pub tls_enabled: bool,
+  /// Url of an OpenTelemetry endpoint to send tracing data to (optional)
+  #[default(None)]
+  pub opentelemetry_url: Option<Url>,
+  /// Maximum number of HTTP requests allowed when fetching a single remote object
+  #[default(20)]
+  pub http_fetch_retry_limit: i32,
+  /// Maximum length of local community and user names
+  #[default(20)]
+  pub actor_name_max_length: usize,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, SmartDefault, Document)]
+#[serde(default)]
+pub struct Federation {
+  /// Whether to enable activitypub federation.
+  #[default(false)]
+  pub enabled: bool,
+  /// Allows and blocks federation with a list of instances. Defaults to allowing all.
+  #[default(None)]
+  #[doku(example = "instance1.tld")]
+  #[doku(example = "instance2.tld")]
+  pub allowed_instances: Option<Vec<String>>,
+  /// Blocks federation with a list of instances.
+  #[default(None)]
+  #[doku(example = "instance1.tld")]
+  #[doku(example = "instance2.tld")]
+  pub blocked_instances: Option<Vec<String>>,
+  /// If true, only federate with instances on the allowlist and block everything else. If false,
+  /// use allowlist only for remote communities, and posts/comments in local communities.
+  #[default(false)]
+  pub strict_allowlist: bool,
+  /// Require a valid HTTP signature on incoming object fetches (authorized fetch / secure mode),
+  /// and sign our own outbound fetches with the local actor key. When enabled, instances we block
+  /// can no longer read our content anonymously.
+  #[default(false)]
+  pub secure_mode: bool,
+  /// How many days to keep sent/received activities in the federation debug log before a periodic
+  /// task prunes them. Set to 0 to keep them forever.
+  #[default(7)]
+  pub keep_activities_days: i64,
+  /// Number of workers for sending outgoing activities.
+  #[default(64)]
+  pub worker_count: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, SmartDefault, Document)]
+#[serde(default)]
+pub struct CaptchaConfig {
+  /// Whether captcha is required for signup
+  #[default(false)]
+  pub enabled: bool,
+  /// Can be easy, medium, or hard
+  #[default("medium")]
+  pub difficulty: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, SmartDefault, Document)]
+#[serde(default)]
+pub struct DatabaseConfig {
+  /// Username to connect to postgres
+  #[default("lemmy")]
+  pub user: String,
+  /// Password to connect to postgres
+  #[default("password")]
+  pub password: String,
+  #[default("localhost")]
+  /// Host where postgres is running
+  pub host: String,
+  /// Port where postgres can be accessed
+  #[default(5432)]
+  pub port: i32,
+  /// Name of the postgres database for lemmy
+  #[default("lemmy")]
+  pub database: String,
+  /// Maximum number of active sql connections
+  #[default(5)]
+  pub pool_size: u32,
+}
+
+impl DatabaseConfig {
+  pub fn connection_url(&self) -> String {
+    match get_database_url_from_env() {
+      Ok(url) => url,
+      Err(_) => format!(
+        "postgres://{}:{}@{}:{}/{}",
+        self.user, self.password, self.host, self.port, self.database
+      ),
+    }
+  }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, SmartDefault, Document)]
+#[serde(default)]
+pub struct EmailConfig {
+  /// Hostname and port of the smtp server
+  #[doku(example = "localhost:25")]
+  pub smtp_server: String,
+  /// Login name for smtp server
+  pub smtp_login: Option<String>,
+  /// Password to login to the smtp server
+  pub smtp_password: Option<String>,
+  #[doku(example = "noreply@example.com")]
+  /// Address to send emails from, eg "noreply@your-instance.com"
+  pub smtp_from_address: String,
+  /// Whether or not smtp connections should use tls. Can be none, tls, or starttls
+  #[default("none")]
+  pub tls_type: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, SmartDefault, Document)]
+#[serde(default)]
+pub struct SetupConfig {
+  /// Username for the admin user
+  #[doku(example = "admin")]
+  pub admin_username: String,
+  /// Password for the admin user. It must be at least 10 characters.
+  #[doku(example = "tf6HHDS4RolWfFhk4Rq9")]
+  pub admin_password: String,
+  /// Name of the site (can be changed later)
+  #[doku(example = "My Lemmy Instance")]
+  pub site_name: String,
+  /// Email for the admin user (optional, can be omitted and set later through the website)
+  #[default(None)]
+  #[doku(example = "user@example.com")]
+  pub admin_email: Option<String>,
+}
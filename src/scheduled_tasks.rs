@@ -0,0 +1,39 @@
+use chrono::Duration;
+use clokwerk::{Scheduler, TimeUnits};
+use diesel::{Connection, PgConnection};
+use lemmy_db_schema::{source::activity::Activity, utils::naive_now};
+use lemmy_utils::{error::LemmyError, settings::structs::Settings};
+use std::{thread, time::Duration as StdDuration};
+use tracing::{info, warn};
+
+/// Schedules and runs the periodic federation/maintenance tasks. Runs on its own thread with a
+/// dedicated connection, since clokwerk tasks are synchronous.
+pub fn setup(db_url: String) -> Result<(), LemmyError> {
+  let mut scheduler = Scheduler::new();
+
+  let conn = PgConnection::establish(&db_url).expect("could not establish connection");
+  // Prune the federation debug log once a day, honoring the configured retention window.
+  scheduler.every(1.day()).run(move || {
+    clear_old_activities(&conn);
+  });
+
+  // Manually run the scheduler in an event loop.
+  loop {
+    scheduler.run_pending();
+    thread::sleep(StdDuration::from_millis(1000));
+  }
+}
+
+/// Deletes stored activities older than `federation.keep_activities_days`. A value of `0` keeps
+/// them forever.
+fn clear_old_activities(conn: &PgConnection) {
+  let keep_days = Settings::get().federation.keep_activities_days;
+  if keep_days <= 0 {
+    return;
+  }
+  let cutoff = naive_now() - Duration::days(keep_days);
+  match Activity::delete_older_than(conn, cutoff) {
+    Ok(deleted) => info!("Pruned {} old federation activities", deleted),
+    Err(e) => warn!("Failed to prune old federation activities: {}", e),
+  }
+}